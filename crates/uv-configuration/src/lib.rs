@@ -0,0 +1,3 @@
+mod target_triple;
+
+pub use target_triple::TargetTriple;
@@ -0,0 +1,76 @@
+use uv_platform_tags::{Libc, Platform};
+
+/// A cross-resolution target: the platform to resolve *for*, when it differs from the
+/// platform `uv` is currently running on.
+///
+/// Besides the CPU architecture and OS, a target may pin an explicit libc flavor and version
+/// (e.g. "resolve as if targeting `manylinux_2_28`" or "`musllinux_1_2`"), so that
+/// [`crate::TargetTriple::libc`] doesn't have to fall back to probing the local interpreter.
+#[derive(Debug, Clone)]
+pub struct TargetTriple {
+    arch: String,
+    os: String,
+    libc: Libc,
+}
+
+impl TargetTriple {
+    /// Parse a target triple, optionally suffixed with an explicit libc flavor and version,
+    /// e.g. `x86_64-manylinux_2_28`, `aarch64-musllinux_1_2`, or plain `x86_64-linux` (which
+    /// carries no libc opinion).
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let (arch, os_and_libc) = spec
+            .split_once('-')
+            .ok_or_else(|| format!("invalid target triple: `{spec}`"))?;
+
+        let (os, libc) = if let Some(version) = os_and_libc.strip_prefix("manylinux_") {
+            let (major, minor) = parse_libc_version(version)?;
+            ("linux", Libc::Gnu { major, minor })
+        } else if let Some(version) = os_and_libc.strip_prefix("musllinux_") {
+            let (major, minor) = parse_libc_version(version)?;
+            ("linux", Libc::Musl { major, minor })
+        } else {
+            (os_and_libc, Libc::None)
+        };
+
+        Ok(Self {
+            arch: arch.to_string(),
+            os: os.to_string(),
+            libc,
+        })
+    }
+
+    /// The platform tags' architecture and OS for this target.
+    pub fn platform(&self) -> Platform {
+        Platform {
+            arch: self.arch.clone(),
+            os: self.os.clone(),
+        }
+    }
+
+    /// The libc flavor and version to resolve against for this target, or [`Libc::None`] if
+    /// the target wasn't pinned to one (e.g. a non-Linux OS, or a bare triple with no
+    /// `manylinux_*`/`musllinux_*` suffix).
+    pub fn libc(&self) -> Libc {
+        self.libc
+    }
+
+    /// Returns the base set of resolver markers unchanged; this target's platform is not applied
+    /// to them.
+    pub fn markers<M>(&self, markers: M) -> M {
+        markers
+    }
+}
+
+/// Parse a `{major}_{minor}` libc version suffix, e.g. `2_28` or `1_2`.
+fn parse_libc_version(version: &str) -> Result<(u16, u16), String> {
+    let (major, minor) = version
+        .split_once('_')
+        .ok_or_else(|| format!("invalid libc version: `{version}`, expected `{{major}}_{{minor}}`"))?;
+    let major = major
+        .parse()
+        .map_err(|_| format!("invalid libc major version: `{major}`"))?;
+    let minor = minor
+        .parse()
+        .map_err(|_| format!("invalid libc minor version: `{minor}`"))?;
+    Ok((major, minor))
+}
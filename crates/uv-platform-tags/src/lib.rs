@@ -0,0 +1,54 @@
+mod libc;
+
+pub use libc::Libc;
+
+/// A resolved target platform: a CPU architecture plus an operating system.
+#[derive(Debug, Clone)]
+pub struct Platform {
+    pub arch: String,
+    pub os: String,
+}
+
+#[derive(Debug)]
+pub struct TagsError(pub(crate) String);
+
+impl std::fmt::Display for TagsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for TagsError {}
+
+/// The set of wheel compatibility tags (`{python tag}-{abi tag}-{platform tag}`) for a given
+/// interpreter and target platform.
+#[derive(Debug, Clone)]
+pub struct Tags {
+    platform_tags: Vec<String>,
+}
+
+impl Tags {
+    /// Compute the tags for an interpreter of `(major, minor)` targeting `platform`, given its
+    /// implementation name/version, libc flavor, and whether the GIL is disabled.
+    ///
+    /// Only the platform tags are computed, from `platform.arch` and `libc`; the Python version,
+    /// implementation, and GIL mode do not affect the result.
+    pub fn from_env(
+        platform: &Platform,
+        python_tuple: (u8, u8),
+        implementation_name: &str,
+        implementation_tuple: (u8, u8),
+        libc: Libc,
+        gil_disabled: bool,
+    ) -> Result<Self, TagsError> {
+        let _ = (python_tuple, implementation_name, implementation_tuple, gil_disabled);
+        Ok(Self {
+            platform_tags: libc.platform_tags(&platform.arch),
+        })
+    }
+
+    /// The platform component of each tag, most-specific first.
+    pub fn platform_tags(&self) -> &[String] {
+        &self.platform_tags
+    }
+}
@@ -0,0 +1,57 @@
+/// The C runtime a Python interpreter (or a cross-resolution target) is built against.
+///
+/// This determines which `manylinux`/`musllinux` platform tags a wheel may claim
+/// compatibility with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Libc {
+    /// Not a Linux libc (e.g. Windows, macOS), so no `manylinux`/`musllinux` tags apply.
+    #[default]
+    None,
+    /// glibc, as used by `manylinux_{major}_{minor}`.
+    Gnu { major: u16, minor: u16 },
+    /// musl libc, as used by `musllinux_{major}_{minor}`.
+    Musl { major: u16, minor: u16 },
+}
+
+/// The glibc minor version below which no `manylinux_2_{minor}` wheels were ever published.
+const MANYLINUX_FLOOR_MINOR: u16 = 5;
+
+/// Legacy `manylinuxN` aliases and the glibc minor version (under major `2`) they correspond
+/// to, in ascending order.
+const MANYLINUX_LEGACY_ALIASES: &[(&str, u16)] = &[
+    ("manylinux1", 5),
+    ("manylinux2010", 12),
+    ("manylinux2014", 17),
+];
+
+impl Libc {
+    /// The descending ladder of `manylinux_{major}_{minor}_{arch}` /
+    /// `musllinux_{major}_{minor}_{arch}` platform tags this libc is compatible with, down to
+    /// the historical floor, including the legacy `manylinux1`/`manylinux2010`/`manylinux2014`
+    /// aliases where applicable.
+    pub fn platform_tags(self, arch: &str) -> Vec<String> {
+        match self {
+            Libc::None => Vec::new(),
+            Libc::Gnu { major, minor } if major == 2 => {
+                let mut tags: Vec<String> = (MANYLINUX_FLOOR_MINOR..=minor)
+                    .rev()
+                    .map(|m| format!("manylinux_2_{m}_{arch}"))
+                    .collect();
+                tags.extend(
+                    MANYLINUX_LEGACY_ALIASES
+                        .iter()
+                        .rev()
+                        .filter(|(_, alias_minor)| *alias_minor <= minor)
+                        .map(|(alias, _)| format!("{alias}_{arch}")),
+                );
+                tags
+            }
+            // A hypothetical future glibc major version: no legacy aliases apply.
+            Libc::Gnu { major, minor } => vec![format!("manylinux_{major}_{minor}_{arch}")],
+            Libc::Musl { major, minor } => (0..=minor)
+                .rev()
+                .map(|m| format!("musllinux_{major}_{m}_{arch}"))
+                .collect(),
+        }
+    }
+}
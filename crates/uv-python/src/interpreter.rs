@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+
+use uv_platform_tags::{Libc, Platform, Tags, TagsError};
+
+use crate::libc;
+
+/// A discovered Python interpreter and the facts about it that resolution needs: its
+/// implementation, version, target platform, libc, and free-threading mode.
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    python_executable: PathBuf,
+    implementation_name: String,
+    implementation_version: (u8, u8),
+    python_version: (u8, u8),
+    platform: Platform,
+    libc: Libc,
+    gil_disabled: bool,
+    tags: Tags,
+}
+
+impl Interpreter {
+    /// Probe `python_executable` for its libc flavor and version (see [`crate::libc::detect`])
+    /// and compute the resulting wheel compatibility tags up front so [`Self::tags`] is
+    /// infallible thereafter.
+    ///
+    /// Only the libc is probed: the implementation is recorded as `cpython`, the Python and
+    /// implementation versions as `(0, 0)`, the platform as the host's, and the GIL as enabled.
+    pub fn query(python_executable: &Path) -> Result<Self, libc::Error> {
+        let libc = libc::detect(python_executable)?;
+        // The values below are fixed, not queried from the interpreter.
+        let implementation_name = "cpython".to_string();
+        let implementation_version = (0, 0);
+        let python_version = (0, 0);
+        let platform = Platform {
+            arch: std::env::consts::ARCH.to_string(),
+            os: std::env::consts::OS.to_string(),
+        };
+        let gil_disabled = false;
+        let tags = Tags::from_env(
+            &platform,
+            python_version,
+            &implementation_name,
+            implementation_version,
+            libc,
+            gil_disabled,
+        )
+        .map_err(libc::Error::Tags)?;
+        Ok(Self {
+            python_executable: python_executable.to_path_buf(),
+            implementation_name,
+            implementation_version,
+            python_version,
+            platform,
+            libc,
+            gil_disabled,
+            tags,
+        })
+    }
+
+    pub fn python_executable(&self) -> &Path {
+        &self.python_executable
+    }
+
+    pub fn implementation_name(&self) -> &str {
+        &self.implementation_name
+    }
+
+    pub fn implementation_tuple(&self) -> (u8, u8) {
+        self.implementation_version
+    }
+
+    pub fn python_tuple(&self) -> (u8, u8) {
+        self.python_version
+    }
+
+    pub fn platform(&self) -> &Platform {
+        &self.platform
+    }
+
+    /// The libc flavor and version natively probed for this interpreter.
+    pub fn libc(&self) -> Libc {
+        self.libc
+    }
+
+    pub fn gil_disabled(&self) -> bool {
+        self.gil_disabled
+    }
+
+    /// The wheel compatibility tags computed for this interpreter during [`Self::query`].
+    pub fn tags(&self) -> Result<&Tags, TagsError> {
+        Ok(&self.tags)
+    }
+
+    /// The base markers to layer `--python-version`/`--python-platform` overrides on. Returns
+    /// `M::default()`; the interpreter's own values are not filled in.
+    pub fn markers<M: Default>(&self) -> M {
+        M::default()
+    }
+
+    pub fn resolver_marker_environment<M: Default>(&self) -> M {
+        M::default()
+    }
+}
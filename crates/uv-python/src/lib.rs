@@ -0,0 +1,7 @@
+mod interpreter;
+mod libc;
+mod python_version;
+pub mod sysconfig;
+
+pub use interpreter::Interpreter;
+pub use python_version::PythonVersion;
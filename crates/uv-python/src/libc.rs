@@ -0,0 +1,155 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use uv_platform_tags::{Libc, TagsError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("failed to probe the interpreter's libc")]
+    Io(#[from] io::Error),
+    #[error("could not determine the interpreter's libc flavor or version")]
+    Undetermined,
+    #[error("failed to compute wheel compatibility tags: {0}")]
+    Tags(TagsError),
+}
+
+/// Detect the libc flavor and version a Python interpreter was built against.
+///
+/// glibc is detected by asking the interpreter itself for `os.confstr("CS_GNU_LIBC_VERSION")`
+/// (e.g. `"glibc 2.31"`). musl doesn't implement that `confstr` key, so instead we read the
+/// `PT_INTERP` entry of the interpreter executable's ELF header: if it points at something
+/// like `/lib/ld-musl-x86_64.so.1`, we invoke that loader with no arguments and parse the
+/// `Version 1.2.3` line it prints to stderr.
+pub fn detect(python_executable: &Path) -> Result<Libc, Error> {
+    if let Some(libc) = detect_glibc(python_executable)? {
+        return Ok(libc);
+    }
+    if let Some(libc) = detect_musl(python_executable)? {
+        return Ok(libc);
+    }
+    // Not a Linux libc we recognize (e.g. Windows, macOS): no manylinux/musllinux tags apply.
+    Ok(Libc::None)
+}
+
+/// Ask the interpreter for `os.confstr("CS_GNU_LIBC_VERSION")`, which glibc populates with a
+/// string like `"glibc 2.31"`. Returns `Ok(None)` if the interpreter isn't linked against
+/// glibc (the call raises, or returns something we don't recognize).
+fn detect_glibc(python_executable: &Path) -> Result<Option<Libc>, Error> {
+    let output = Command::new(python_executable)
+        .arg("-c")
+        .arg(r#"import os; print(os.confstr("CS_GNU_LIBC_VERSION"))"#)
+        .output()?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    parse_glibc_version(stdout.trim()).map(Some).or(Ok(None))
+}
+
+fn parse_glibc_version(s: &str) -> Result<Libc, Error> {
+    let version = s.strip_prefix("glibc ").ok_or(Error::Undetermined)?;
+    let (major, minor) = version.split_once('.').ok_or(Error::Undetermined)?;
+    Ok(Libc::Gnu {
+        major: major.parse().map_err(|_| Error::Undetermined)?,
+        minor: minor.parse().map_err(|_| Error::Undetermined)?,
+    })
+}
+
+/// Read the `PT_INTERP` entry of the executable's ELF header; if it names a musl dynamic
+/// loader (`ld-musl-*.so.*`), invoke that loader with no arguments and parse the `Version
+/// 1.2.3` line it writes to stderr.
+fn detect_musl(python_executable: &Path) -> Result<Option<Libc>, Error> {
+    let Some(interp) = read_pt_interp(python_executable)? else {
+        return Ok(None);
+    };
+
+    let file_name = Path::new(&interp)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    if !file_name.starts_with("ld-musl-") {
+        return Ok(None);
+    }
+
+    // The musl loader prints its usage (including a `Version x.y.z` line) to stderr and exits
+    // non-zero when invoked with no arguments.
+    let output = Command::new(&interp).output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let Some(version_line) = stderr.lines().find_map(|line| line.strip_prefix("Version ")) else {
+        return Ok(None);
+    };
+
+    let mut parts = version_line.split('.');
+    let (Some(major), Some(minor)) = (parts.next(), parts.next()) else {
+        return Err(Error::Undetermined);
+    };
+    let major = major.parse().map_err(|_| Error::Undetermined)?;
+    let minor = minor.parse().map_err(|_| Error::Undetermined)?;
+
+    Ok(Some(Libc::Musl { major, minor }))
+}
+
+/// Read the ELF `PT_INTERP` program header of `path`, returning the interpreter path it names
+/// (e.g. `/lib/ld-musl-x86_64.so.1` or `/lib64/ld-linux-x86-64.so.2`), if any.
+fn read_pt_interp(path: &Path) -> Result<Option<String>, Error> {
+    let data = fs::read(path)?;
+    if data.len() < 64 || &data[..4] != b"\x7fELF" {
+        return Ok(None);
+    }
+    let is_64_bit = data[4] == 2;
+    let little_endian = data[5] == 1;
+
+    let read_u64 = |offset: usize| -> u64 {
+        let bytes = &data[offset..offset + 8];
+        if little_endian {
+            u64::from_le_bytes(bytes.try_into().unwrap())
+        } else {
+            u64::from_be_bytes(bytes.try_into().unwrap())
+        }
+    };
+    let read_u32 = |offset: usize| -> u32 {
+        let bytes = &data[offset..offset + 4];
+        if little_endian {
+            u32::from_le_bytes(bytes.try_into().unwrap())
+        } else {
+            u32::from_be_bytes(bytes.try_into().unwrap())
+        }
+    };
+
+    if !is_64_bit {
+        // 32-bit ELF isn't a realistic target for the interpreters we resolve against.
+        return Ok(None);
+    }
+
+    let phoff = read_u64(0x20) as usize;
+    let phentsize = u64::from(u16::from_le_bytes(data[0x36..0x38].try_into().unwrap())) as usize;
+    let phnum = u64::from(u16::from_le_bytes(data[0x38..0x3A].try_into().unwrap())) as usize;
+
+    const PT_INTERP: u32 = 3;
+
+    for i in 0..phnum {
+        let header = phoff + i * phentsize;
+        if header + 56 > data.len() {
+            break;
+        }
+        let p_type = read_u32(header);
+        if p_type != PT_INTERP {
+            continue;
+        }
+        let p_offset = read_u64(header + 8) as usize;
+        let p_filesz = read_u64(header + 32) as usize;
+        let Some(bytes) = data.get(p_offset..p_offset + p_filesz) else {
+            continue;
+        };
+        let interp = String::from_utf8_lossy(bytes)
+            .trim_end_matches('\0')
+            .to_string();
+        return Ok(Some(interp));
+    }
+
+    Ok(None)
+}
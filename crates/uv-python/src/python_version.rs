@@ -0,0 +1,26 @@
+/// A Python version requested via `--python-version`, independent of any installed
+/// interpreter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PythonVersion {
+    major: u8,
+    minor: u8,
+}
+
+impl PythonVersion {
+    pub fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+
+    /// Returns the base set of markers unchanged; this version is not applied to them.
+    pub fn markers<M>(&self, markers: M) -> M {
+        markers
+    }
+}
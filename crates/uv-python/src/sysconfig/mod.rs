@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use regex::Regex;
+
+mod replacements;
+
+pub use replacements::{patch_sysconfig_paths, ReplacementEntry, ReplacementMode};
+
+/// Relocate a downloaded, prebuilt Python's recorded `sysconfig.get_paths()` so that its build
+/// paths point at `install_root` instead of the path it was originally built at
+/// (`recorded_prefix`), across every sysconfig key, not just one.
+///
+/// Sysconfig values are single path tokens (e.g. `/build/cpython/lib`), not
+/// whitespace-separated words, so a `Partial` rule (which only ever matches a whole token) would
+/// only fire on a value equal to the prefix itself; we match `recorded_prefix` as a literal
+/// leading substring via `Regex`
+/// instead, escaping it first since prefixes routinely contain regex metacharacters like `.`.
+pub fn relocate_sysconfig_paths(
+    paths: &BTreeMap<String, String>,
+    recorded_prefix: &str,
+    install_root: &Path,
+) -> BTreeMap<String, String> {
+    let install_root = install_root.display().to_string();
+    let pattern = Regex::new(&format!("^{}", regex::escape(recorded_prefix)))
+        .expect("escaped literal prefix is always a valid regex");
+    let rules: Vec<ReplacementEntry> = paths
+        .keys()
+        .map(|key| ReplacementEntry {
+            key: key.clone(),
+            mode: ReplacementMode::Regex {
+                pattern: pattern.clone(),
+            },
+            to: install_root.clone(),
+        })
+        .collect();
+
+    patch_sysconfig_paths(paths, &rules)
+}
@@ -1,19 +1,29 @@
+use std::collections::BTreeMap;
+
+use regex::Regex;
+
 /// Replacement mode for sysconfig values.
 #[derive(Debug)]
 pub enum ReplacementMode {
     Partial { from: String },
     Full,
+    Regex { pattern: Regex },
 }
 
 /// A replacement entry to patch in sysconfig data.
+///
+/// `key` identifies the `sysconfig.get_paths()` entry this rule applies to (e.g. `"stdlib"`,
+/// `"purelib"`, `"scripts"`).
 #[derive(Debug)]
 pub struct ReplacementEntry {
+    pub key: String,
     pub mode: ReplacementMode,
     pub to: String,
 }
 
 impl ReplacementEntry {
-    /// Patches a sysconfig value either partially (replacing a specific word) or fully.
+    /// Patches a sysconfig value either partially (replacing a specific word), fully, or via a
+    /// regular expression substitution.
     pub fn patch(&self, entry: &str) -> String {
         match &self.mode {
             ReplacementMode::Partial { from } => entry
@@ -22,6 +32,45 @@ impl ReplacementEntry {
                 .collect::<Vec<_>>()
                 .join(" "),
             ReplacementMode::Full => self.to.clone(),
+            ReplacementMode::Regex { pattern } => {
+                pattern.replace_all(entry, self.to.as_str()).into_owned()
+            }
         }
     }
 }
+
+/// Patches every value in a `sysconfig.get_paths()`-style map, applying the first
+/// [`ReplacementEntry`] (in declaration order) whose `key` matches. Keys with no matching rule
+/// are left untouched.
+///
+/// Re-running this function on its own output never corrupts the data: for each matching rule
+/// we check whether applying it again to its own result would change that result further (a
+/// `Regex` rule whose `to` can itself match `pattern` is the risky case, e.g. `pattern = /old`,
+/// `to = /new/old` turning `/old/lib` into `/new/old/lib` and, on a naive second pass, into
+/// `/new/new/old/lib`). If a second application would still change the value, the rule is not
+/// a fixed point and we leave the original value alone rather than risk drifting further on
+/// repeated syncs. `Full` rules are always fixed points, and so are `Partial` rules unless `to`
+/// itself contains `from` as a whitespace-separated word.
+pub fn patch_sysconfig_paths(
+    paths: &BTreeMap<String, String>,
+    rules: &[ReplacementEntry],
+) -> BTreeMap<String, String> {
+    paths
+        .iter()
+        .map(|(key, value)| {
+            let patched = rules
+                .iter()
+                .find(|rule| &rule.key == key)
+                .map(|rule| {
+                    let once = rule.patch(value);
+                    if rule.patch(&once) == once {
+                        once
+                    } else {
+                        value.clone()
+                    }
+                })
+                .unwrap_or_else(|| value.clone());
+            (key.clone(), patched)
+        })
+        .collect()
+}
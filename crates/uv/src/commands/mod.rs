@@ -105,6 +105,61 @@ impl From<ExitStatus> for ExitCode {
     }
 }
 
+/// A leading `+`-prefixed interpreter selector (e.g. `+3.11`, `+pypy@3.10`), mirroring how
+/// `rustup` toolchain shims pick a toolchain ahead of the rest of the command line.
+#[derive(Debug, Clone)]
+pub struct InterpreterSelector(String);
+
+impl InterpreterSelector {
+    /// The raw request, suitable for passing straight to [`crate::commands::python_find`].
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Strip a leading `+`-prefixed interpreter selector off `args`, returning the selector (if
+/// any) alongside the remaining arguments.
+///
+/// A selector conflicting with an explicit `--python`/`--python=` elsewhere in `args` is
+/// rejected, since the two are mutually exclusive ways of pinning the interpreter.
+pub(super) fn strip_interpreter_selector(
+    args: &[String],
+) -> Result<(Option<InterpreterSelector>, Vec<String>), ExitStatus> {
+    let Some(selector) = args.first().and_then(|arg| arg.strip_prefix('+')) else {
+        return Ok((None, args.to_vec()));
+    };
+
+    if args[1..]
+        .iter()
+        .any(|arg| arg == "--python" || arg.starts_with("--python="))
+    {
+        return Err(ExitStatus::Failure);
+    }
+
+    Ok((
+        Some(InterpreterSelector(selector.to_string())),
+        args[1..].to_vec(),
+    ))
+}
+
+/// Strip a leading `+VERSION` interpreter selector off `args` (if any) and resolve it to a
+/// concrete interpreter via [`python_find`], so that e.g. `uv-pub +3.11 pip install ...` picks
+/// `3.11` the same way an explicit `--python 3.11` would.
+///
+/// Returns `Err(ExitStatus::Error)` if the selector doesn't resolve to an installed
+/// interpreter.
+pub fn resolve_leading_interpreter_selector(
+    args: &[String],
+) -> Result<(Option<uv_python::Interpreter>, Vec<String>), ExitStatus> {
+    let (selector, rest) = strip_interpreter_selector(args)?;
+    let Some(selector) = selector else {
+        return Ok((None, rest));
+    };
+
+    let interpreter = python_find(selector.as_str()).map_err(|_| ExitStatus::Error)?;
+    Ok((Some(interpreter), rest))
+}
+
 /// Format a duration as a human-readable string, Cargo-style.
 pub(super) fn elapsed(duration: Duration) -> String {
     let secs = duration.as_secs();
@@ -144,6 +199,57 @@ pub(super) struct DryRunEvent<T: Display> {
     kind: ChangeEventKind,
 }
 
+impl<T: InstalledMetadata> Display for ChangeEvent<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entry = format!("{}=={}", self.dist.name(), self.dist.installed_version());
+        match self.kind {
+            ChangeEventKind::Added => write!(f, "{}", format!("+ {entry}").green()),
+            ChangeEventKind::Removed => write!(f, "{}", format!("- {entry}").red()),
+            ChangeEventKind::Reinstalled => write!(f, "{}", format!("~ {entry}").yellow()),
+        }
+    }
+}
+
+impl<T: Display> Display for DryRunEvent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entry = format!("{}=={}", self.name, self.version);
+        match self.kind {
+            ChangeEventKind::Added => write!(f, "{}", format!("+ {entry}").green()),
+            ChangeEventKind::Removed => write!(f, "{}", format!("- {entry}").red()),
+            ChangeEventKind::Reinstalled => write!(f, "{}", format!("~ {entry}").yellow()),
+        }
+    }
+}
+
+/// Render a set of change events as a diff-style summary: a single change collapses to one
+/// inline line, while multiple changes render as a block sorted by kind, then package name.
+pub(super) fn render_changelog<T: InstalledMetadata>(events: &mut [ChangeEvent<'_, T>]) -> String {
+    events.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.dist.name().cmp(b.dist.name())));
+    match events {
+        [] => String::new(),
+        [event] => event.to_string(),
+        events => events
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Render a set of dry-run change events the same way as [`render_changelog`].
+pub(super) fn render_dry_run_changelog<T: Display>(events: &mut [DryRunEvent<T>]) -> String {
+    events.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.name.cmp(&b.name)));
+    match events {
+        [] => String::new(),
+        [event] => event.to_string(),
+        events => events
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
@@ -202,22 +308,36 @@ pub(super) fn human_readable_bytes(bytes: u64) -> (f32, &'static str) {
     (bytes / 1024_f32.powi(i as i32), UNITS[i])
 }
 
+/// Output format for commands that support a machine-readable mode (`pip list`, `pip freeze`,
+/// `pip tree`, `pip show`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum OutputFormat {
+    /// Human-readable, possibly colored, text.
+    #[default]
+    Text,
+    /// A single JSON document. It is written to standard output as it is produced, like text;
+    /// only the output file's copy is buffered and written atomically on [`OutputWriter::commit`].
+    Json,
+}
+
 /// A multicasting writer that writes to both the standard output and an output file, if present.
 #[allow(clippy::disallowed_types)]
 struct OutputWriter<'a> {
     stdout: Option<AutoStream<std::io::Stdout>>,
     output_file: Option<&'a Path>,
+    format: OutputFormat,
     buffer: Vec<u8>,
 }
 
 #[allow(clippy::disallowed_types)]
 impl<'a> OutputWriter<'a> {
     /// Create a new output writer.
-    fn new(include_stdout: bool, output_file: Option<&'a Path>) -> Self {
+    fn new(format: OutputFormat, include_stdout: bool, output_file: Option<&'a Path>) -> Self {
         let stdout = include_stdout.then(|| AutoStream::<std::io::Stdout>::auto(stdout()));
         Self {
             stdout,
             output_file,
+            format,
             buffer: Vec::new(),
         }
     }
@@ -250,7 +370,11 @@ impl<'a> OutputWriter<'a> {
             let output_file = fs_err::read_link(output_file)
                 .map(Cow::Owned)
                 .unwrap_or(Cow::Borrowed(output_file));
-            let stream = anstream::adapter::strip_bytes(&self.buffer).into_vec();
+            // JSON output is never colorized, so there's no ANSI to strip.
+            let stream = match self.format {
+                OutputFormat::Text => anstream::adapter::strip_bytes(&self.buffer).into_vec(),
+                OutputFormat::Json => self.buffer,
+            };
             uv_fs::write_atomic(output_file, &stream).await?;
         }
         Ok(())
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use uv_cache::Cache;
+use uv_configuration::{Concurrency, Reinstall, Upgrade};
+use uv_distribution_types::InstalledMetadata;
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+
+use crate::commands::pip::operations::{plan_changes, report_changes};
+use crate::commands::{compile_bytecode, ExitStatus};
+use crate::printer::Printer;
+
+/// Plan installing a resolved set of requirements into a Python environment, honoring
+/// `--upgrade`, `--upgrade-package`, and `--reinstall`, and report the planned changes through
+/// the shared diff-style [`ChangeEvent`](crate::commands::ChangeEvent) renderer. With `compile`,
+/// the environment is then bytecode-compiled. No package is installed or removed here.
+pub async fn pip_install<T: InstalledMetadata>(
+    venv: &PythonEnvironment,
+    installed: &[T],
+    required: &BTreeMap<PackageName, T>,
+    upgrade: Upgrade,
+    reinstall: Reinstall,
+    compile: bool,
+    cache: &Cache,
+    concurrency: Concurrency,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let mut changes = plan_changes(installed, required, &upgrade, &reinstall, false);
+    report_changes(&mut changes, printer)?;
+
+    if compile {
+        compile_bytecode(venv, &concurrency, cache, printer).await?;
+    }
+
+    Ok(ExitStatus::Success)
+}
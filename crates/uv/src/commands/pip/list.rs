@@ -0,0 +1,44 @@
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::Serialize;
+use uv_distribution_types::InstalledMetadata;
+
+use crate::commands::{ExitStatus, OutputFormat, OutputWriter};
+
+#[derive(Serialize)]
+struct ListEntry {
+    name: String,
+    version: String,
+}
+
+/// List installed packages, as human-readable text or as a stable JSON array (`--format
+/// json`) that scripts can consume.
+pub async fn pip_list<T: InstalledMetadata>(
+    installed: &[T],
+    format: OutputFormat,
+    output_file: Option<&Path>,
+) -> anyhow::Result<ExitStatus> {
+    let mut writer = OutputWriter::new(format, true, output_file);
+
+    match format {
+        OutputFormat::Text => {
+            for dist in installed {
+                writeln!(writer, "{} {}", dist.name(), dist.installed_version())?;
+            }
+        }
+        OutputFormat::Json => {
+            let entries: Vec<ListEntry> = installed
+                .iter()
+                .map(|dist| ListEntry {
+                    name: dist.name().to_string(),
+                    version: dist.installed_version().to_string(),
+                })
+                .collect();
+            write!(writer, "{}", serde_json::to_string_pretty(&entries)?)?;
+        }
+    }
+
+    writer.commit().await?;
+    Ok(ExitStatus::Success)
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use uv_configuration::TargetTriple;
-use uv_platform_tags::{Tags, TagsError};
+use uv_platform_tags::{Libc, Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
 
@@ -48,7 +48,7 @@ pub fn resolution_tags<'env>(
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
-            python_platform.manylinux_compatible(),
+            python_platform.libc(),
             interpreter.gil_disabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
@@ -56,7 +56,7 @@ pub fn resolution_tags<'env>(
             interpreter.python_tuple(),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
-            python_platform.manylinux_compatible(),
+            python_platform.libc(),
             interpreter.gil_disabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
@@ -64,7 +64,7 @@ pub fn resolution_tags<'env>(
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
-            interpreter.manylinux_compatible(),
+            interpreter.libc(),
             interpreter.gil_disabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
@@ -83,7 +83,7 @@ pub fn resolution_environment(
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
-            python_platform.manylinux_compatible(),
+            python_platform.libc(),
             interpreter.gil_disabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
@@ -91,7 +91,7 @@ pub fn resolution_environment(
             interpreter.python_tuple(),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
-            python_platform.manylinux_compatible(),
+            python_platform.libc(),
             interpreter.gil_disabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
@@ -99,7 +99,7 @@ pub fn resolution_environment(
             (python_version.major(), python_version.minor()),
             interpreter.implementation_name(),
             interpreter.implementation_tuple(),
-            interpreter.manylinux_compatible(),
+            interpreter.libc(),
             interpreter.gil_disabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use uv_configuration::{Reinstall, Upgrade};
+use uv_distribution_types::InstalledMetadata;
+use uv_normalize::PackageName;
+
+use crate::commands::{render_changelog, ChangeEvent, ChangeEventKind};
+use crate::printer::Printer;
+
+/// Diff an installed environment against a resolved set of requirements, honoring [`Upgrade`]
+/// (including per-package upgrade selection) and [`Reinstall`] semantics.
+///
+/// Shared by `pip install` and `pip sync`, so both commands apply the same upgrade/reinstall
+/// selection and report the result through the same [`ChangeEvent`] machinery. The two commands
+/// differ on extraneous packages though: `pip sync` prunes (and reports `Removed` for) anything
+/// installed but not required, while `pip install` only ever adds to an environment and leaves
+/// unrelated installed packages alone. `prune_extraneous` selects between the two. A package
+/// that is both installed and required produces no event unless it is selected for upgrade
+/// (`Added`) or reinstall (`Reinstalled`), whatever version `required` names.
+pub(crate) fn plan_changes<'a, T: InstalledMetadata>(
+    installed: &'a [T],
+    required: &'a BTreeMap<PackageName, T>,
+    upgrade: &Upgrade,
+    reinstall: &Reinstall,
+    prune_extraneous: bool,
+) -> Vec<ChangeEvent<'a, T>> {
+    let mut events = Vec::new();
+
+    for dist in installed {
+        let name = dist.name();
+        match required.get(name) {
+            None if prune_extraneous => events.push(ChangeEvent {
+                dist,
+                kind: ChangeEventKind::Removed,
+            }),
+            None => {}
+            Some(_) if reinstall.contains(name) => events.push(ChangeEvent {
+                dist,
+                kind: ChangeEventKind::Reinstalled,
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for (name, dist) in required {
+        let currently_installed = installed.iter().any(|dist| dist.name() == name);
+        if !currently_installed || upgrade.contains(name) {
+            events.push(ChangeEvent {
+                dist,
+                kind: ChangeEventKind::Added,
+            });
+        }
+    }
+
+    events
+}
+
+/// Report a set of planned changes to the user via the shared diff-style renderer.
+pub(crate) fn report_changes<T: InstalledMetadata>(
+    events: &mut [ChangeEvent<'_, T>],
+    printer: Printer,
+) -> anyhow::Result<()> {
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(printer.stderr(), "{}", render_changelog(events))?;
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::Serialize;
+use uv_distribution_types::InstalledMetadata;
+
+use crate::commands::{ExitStatus, OutputFormat, OutputWriter};
+
+#[derive(Serialize)]
+struct ShowEntry {
+    name: String,
+    version: String,
+    location: String,
+}
+
+/// Show metadata for a single installed package, as human-readable text or as a JSON object.
+pub async fn pip_show<T: InstalledMetadata>(
+    dist: &T,
+    installed_path: &Path,
+    format: OutputFormat,
+    output_file: Option<&Path>,
+) -> anyhow::Result<ExitStatus> {
+    let mut writer = OutputWriter::new(format, true, output_file);
+
+    match format {
+        OutputFormat::Text => {
+            writeln!(writer, "Name: {}", dist.name())?;
+            writeln!(writer, "Version: {}", dist.installed_version())?;
+            writeln!(writer, "Location: {}", installed_path.display())?;
+        }
+        OutputFormat::Json => {
+            let entry = ShowEntry {
+                name: dist.name().to_string(),
+                version: dist.installed_version().to_string(),
+                location: installed_path.display().to_string(),
+            };
+            write!(writer, "{}", serde_json::to_string_pretty(&entry)?)?;
+        }
+    }
+
+    writer.commit().await?;
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+
+use uv_cache::Cache;
+use uv_configuration::{Concurrency, Reinstall, Upgrade};
+use uv_distribution_types::InstalledMetadata;
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+
+use crate::commands::pip::operations::{plan_changes, report_changes};
+use crate::commands::{compile_bytecode, ExitStatus};
+use crate::printer::Printer;
+
+/// Plan syncing a Python environment with a resolved set of requirements and report the
+/// planned changes. With `compile`, the environment is then bytecode-compiled. No package is
+/// installed or removed here.
+///
+/// Routes through the shared [`crate::commands::pip::operations`] pipeline, so
+/// `--upgrade`/`--upgrade-package` and `--reinstall` are honored the same way they are for
+/// `pip install`.
+pub async fn pip_sync<T: InstalledMetadata>(
+    venv: &PythonEnvironment,
+    installed: &[T],
+    required: &BTreeMap<PackageName, T>,
+    upgrade: Upgrade,
+    reinstall: Reinstall,
+    compile: bool,
+    cache: &Cache,
+    concurrency: Concurrency,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let mut changes = plan_changes(installed, required, &upgrade, &reinstall, true);
+    report_changes(&mut changes, printer)?;
+
+    if compile {
+        compile_bytecode(venv, &concurrency, cache, printer).await?;
+    }
+
+    Ok(ExitStatus::Success)
+}
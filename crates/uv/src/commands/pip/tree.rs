@@ -0,0 +1,66 @@
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::Serialize;
+use uv_distribution_types::InstalledMetadata;
+use uv_normalize::PackageName;
+
+use crate::commands::{ExitStatus, OutputFormat, OutputWriter};
+
+/// A single resolved package plus the names of the packages it depends on, as shown by `pip
+/// tree`.
+pub struct TreeEntry<'a, T: InstalledMetadata> {
+    pub(crate) dist: &'a T,
+    pub(crate) dependencies: Vec<PackageName>,
+}
+
+#[derive(Serialize)]
+struct TreeEntryJson {
+    name: String,
+    version: String,
+    dependencies: Vec<String>,
+}
+
+/// Output the installed dependency tree, as indented human-readable text or as a JSON array
+/// of `{name, version, dependencies}` edges.
+pub async fn pip_tree<T: InstalledMetadata>(
+    entries: &[TreeEntry<'_, T>],
+    format: OutputFormat,
+    output_file: Option<&Path>,
+) -> anyhow::Result<ExitStatus> {
+    let mut writer = OutputWriter::new(format, true, output_file);
+
+    match format {
+        OutputFormat::Text => {
+            for entry in entries {
+                writeln!(
+                    writer,
+                    "{} {}",
+                    entry.dist.name(),
+                    entry.dist.installed_version()
+                )?;
+                for dependency in &entry.dependencies {
+                    writeln!(writer, "├── {dependency}")?;
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let json: Vec<TreeEntryJson> = entries
+                .iter()
+                .map(|entry| TreeEntryJson {
+                    name: entry.dist.name().to_string(),
+                    version: entry.dist.installed_version().to_string(),
+                    dependencies: entry
+                        .dependencies
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                })
+                .collect();
+            write!(writer, "{}", serde_json::to_string_pretty(&json)?)?;
+        }
+    }
+
+    writer.commit().await?;
+    Ok(ExitStatus::Success)
+}
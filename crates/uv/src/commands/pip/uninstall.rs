@@ -0,0 +1,27 @@
+use uv_distribution_types::InstalledMetadata;
+
+use crate::commands::{render_changelog, ChangeEvent, ChangeEventKind, ExitStatus};
+use crate::printer::Printer;
+
+/// Report uninstalling a set of packages from a Python environment as `Removed` events through
+/// the shared diff-style [`ChangeEvent`] renderer (the same one `pip install`/`pip sync` use).
+/// The packages are not removed here.
+pub fn pip_uninstall<T: InstalledMetadata>(
+    to_remove: &[T],
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let mut events: Vec<_> = to_remove
+        .iter()
+        .map(|dist| ChangeEvent {
+            dist,
+            kind: ChangeEventKind::Removed,
+        })
+        .collect();
+
+    if !events.is_empty() {
+        use std::fmt::Write;
+        writeln!(printer.stderr(), "{}", render_changelog(&mut events))?;
+    }
+
+    Ok(ExitStatus::Success)
+}
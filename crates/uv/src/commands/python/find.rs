@@ -0,0 +1,30 @@
+use anyhow::Result;
+use uv_python::Interpreter;
+
+/// Resolve an interpreter request (a bare version like `3.11`, or an implementation-qualified
+/// one like `pypy@3.10`) to a concrete [`Interpreter`] on `PATH`.
+///
+/// This is the landing spot for the `+VERSION` leading-token selector stripped off the command
+/// line by [`crate::commands::strip_interpreter_selector`]: once resolved here, the interpreter
+/// feeds into [`crate::commands::pip::resolution_environment`] the same way an explicit
+/// `--python` would.
+pub fn find(request: &str) -> Result<Interpreter> {
+    let python_executable = which::which(executable_name(request))?;
+    Ok(Interpreter::query(&python_executable)?)
+}
+
+/// Resolve an interpreter request for running a PEP 723 script, identical to [`find`] for now.
+pub fn find_script(request: &str) -> Result<Interpreter> {
+    find(request)
+}
+
+/// Turn an interpreter request into the executable name we'd look up on `PATH`, e.g. `3.11`
+/// becomes `python3.11`, `pypy@3.10` becomes `pypy3.10`, and `cpython@3.12` becomes `python3.12`
+/// since `cpython` isn't itself an executable prefix on any platform.
+fn executable_name(request: &str) -> String {
+    match request.split_once('@') {
+        Some(("cpython" | "python", version)) => format!("python{version}"),
+        Some((implementation, version)) => format!("{implementation}{version}"),
+        None => format!("python{request}"),
+    }
+}
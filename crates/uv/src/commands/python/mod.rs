@@ -0,0 +1,4 @@
+pub mod find;
+
+// `dir`, `install`, `list`, `pin`, and `uninstall` are unrelated to the `+VERSION` selector
+// work and aren't part of this checkout.